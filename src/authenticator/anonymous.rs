@@ -0,0 +1,40 @@
+use std::time::{Duration, SystemTime};
+
+use oauth2::AccessToken;
+
+use crate::token::{TokenPair, TokenType};
+use crate::AuthError;
+
+use super::Authenticator;
+
+/// A no-op authenticator for endpoints that don't require auth.
+///
+/// `token()`/`bearer()` hand back an empty secret that is never refreshed,
+/// so MOZAIK services can depend on [crate::AuthToken] uniformly even when a
+/// given endpoint has no authentication requirement.
+pub struct AnonymousAuthenticator;
+
+#[async_trait::async_trait]
+impl Authenticator for AnonymousAuthenticator {
+    async fn login(&self) -> Result<TokenPair, AuthError> {
+        Ok(TokenPair {
+            access_token: AccessToken::new(String::new()),
+            refresh_token: None,
+            issued_at: SystemTime::now(),
+            expires_in: Duration::MAX,
+            token_type: TokenType::Bearer,
+        })
+    }
+
+    async fn refresh(&self, current: &TokenPair) -> Result<TokenPair, AuthError> {
+        Ok(current.clone())
+    }
+
+    fn needs_refresh(&self, _current: &TokenPair) -> bool {
+        false
+    }
+
+    fn bearer(&self, _current: &TokenPair) -> String {
+        String::new()
+    }
+}
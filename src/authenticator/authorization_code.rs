@@ -0,0 +1,67 @@
+use oauth2::{basic::BasicClient, RefreshToken, TokenResponse};
+use tokio::sync::RwLock;
+
+use crate::error::map_token_error;
+use crate::http::send_request;
+use crate::token::TokenPair;
+use crate::AuthError;
+
+use super::Authenticator;
+
+/// Authenticates via RFC 6749 §6, refreshing an access token from a
+/// previously-obtained refresh token.
+///
+/// This holds the refresh token from an authorization code exchange that
+/// happened outside this crate (e.g. an interactive browser login) and uses
+/// it to mint new access tokens without re-prompting the user.
+pub struct AuthorizationCodeAuthenticator {
+    client: BasicClient,
+    http_client: reqwest::Client,
+    refresh_token: RwLock<RefreshToken>,
+}
+
+impl AuthorizationCodeAuthenticator {
+    pub fn new(client: BasicClient, refresh_token: RefreshToken) -> Self {
+        AuthorizationCodeAuthenticator {
+            client,
+            http_client: reqwest::Client::new(),
+            refresh_token: RwLock::new(refresh_token),
+        }
+    }
+
+    /// Send requests through `http_client` instead of the client this
+    /// authenticator builds for itself in [AuthorizationCodeAuthenticator::new],
+    /// so its traffic can share a pool with the rest of the service.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    async fn exchange_refresh_token(&self) -> Result<TokenPair, AuthError> {
+        let refresh_token = self.refresh_token.read().await.clone();
+
+        let token_response = self
+            .client
+            .exchange_refresh_token(&refresh_token)
+            .request_async(|request| send_request(&self.http_client, request))
+            .await
+            .map_err(map_token_error)?;
+
+        if let Some(rotated) = token_response.refresh_token() {
+            *self.refresh_token.write().await = rotated.clone();
+        }
+
+        Ok(TokenPair::from_response(&token_response))
+    }
+}
+
+#[async_trait::async_trait]
+impl Authenticator for AuthorizationCodeAuthenticator {
+    async fn login(&self) -> Result<TokenPair, AuthError> {
+        self.exchange_refresh_token().await
+    }
+
+    async fn refresh(&self, _current: &TokenPair) -> Result<TokenPair, AuthError> {
+        self.exchange_refresh_token().await
+    }
+}
@@ -0,0 +1,35 @@
+mod anonymous;
+mod authorization_code;
+mod client_credentials;
+
+pub use anonymous::AnonymousAuthenticator;
+pub use authorization_code::AuthorizationCodeAuthenticator;
+pub use client_credentials::ClientCredentialsAuthenticator;
+
+use crate::token::TokenPair;
+use crate::AuthError;
+
+/// A pluggable strategy for obtaining and refreshing OAuth 2.0 access tokens.
+///
+/// Implementations encapsulate a specific grant type. [crate::AuthToken]
+/// holds one behind an `Arc<dyn Authenticator>` so a service can switch
+/// grant types without changing call sites.
+#[async_trait::async_trait]
+pub trait Authenticator: Send + Sync {
+    /// Obtain the first token, e.g. by exchanging client credentials or an
+    /// authorization code.
+    async fn login(&self) -> Result<TokenPair, AuthError>;
+
+    /// Obtain a new token to replace `current`.
+    async fn refresh(&self, current: &TokenPair) -> Result<TokenPair, AuthError>;
+
+    /// Whether `current` is due for a refresh.
+    fn needs_refresh(&self, current: &TokenPair) -> bool {
+        current.time_until_refresh() == std::time::Duration::ZERO
+    }
+
+    /// The `Authorization` header value to send alongside `current`.
+    fn bearer(&self, current: &TokenPair) -> String {
+        format!("Bearer {}", current.access_token.secret())
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+/// Everything that can go wrong while obtaining or refreshing an OAuth 2.0
+/// access token.
+#[derive(Debug, Error)]
+pub enum AuthError {
+    /// `auth_endpoint` or `token_endpoint` was not a valid URL.
+    #[error("invalid endpoint URL: {0}")]
+    UrlParse(#[from] oauth2::url::ParseError),
+
+    /// The HTTP request to the token endpoint failed (transport error,
+    /// non-JSON body, unexpected status code, ...).
+    #[error("token request failed: {0}")]
+    Request(String),
+
+    /// The token endpoint responded with an OAuth error response, e.g.
+    /// `{"error": "invalid_client", "error_description": "..."}`.
+    #[error("token endpoint returned `{error}`")]
+    OAuth {
+        error: String,
+        description: Option<String>,
+    },
+
+    /// The cached token has fully expired and no refresh has succeeded since.
+    #[error("cached token has expired and no refresh has succeeded")]
+    Expired,
+
+    /// The token endpoint issued a token whose `token_type` isn't `Bearer`,
+    /// so it can't be handed out as one.
+    #[error("token endpoint issued a `{0}` token, expected `Bearer`")]
+    UnexpectedTokenType(String),
+}
+
+/// Turn an [oauth2::basic::BasicRequestTokenError] into an [AuthError],
+/// preserving the server's `error`/`error_description` when one was
+/// returned.
+pub(crate) fn map_token_error<RE>(err: oauth2::basic::BasicRequestTokenError<RE>) -> AuthError
+where
+    RE: std::error::Error + 'static,
+{
+    match err {
+        oauth2::RequestTokenError::ServerResponse(resp) => AuthError::OAuth {
+            error: resp.error().to_string(),
+            description: resp.error_description().cloned(),
+        },
+        other => AuthError::Request(other.to_string()),
+    }
+}
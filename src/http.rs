@@ -0,0 +1,40 @@
+use oauth2::reqwest::Error as HttpClientError;
+use oauth2::{HttpRequest, HttpResponse};
+
+/// Like [oauth2::reqwest::async_http_client], but sends the request through a
+/// caller-supplied [reqwest::Client] instead of building a fresh connection
+/// pool per call.
+///
+/// This lets a service share one pooled client between its auth traffic and
+/// its own API traffic.
+pub(crate) async fn send_request(
+    http_client: &reqwest::Client,
+    request: HttpRequest,
+) -> Result<HttpResponse, HttpClientError<reqwest::Error>> {
+    let mut request_builder = http_client
+        .request(request.method, request.url.as_str())
+        .body(request.body);
+    for (name, value) in &request.headers {
+        request_builder = request_builder.header(name.as_str(), value.as_bytes());
+    }
+    let request = request_builder.build().map_err(HttpClientError::Reqwest)?;
+
+    let response = http_client
+        .execute(request)
+        .await
+        .map_err(HttpClientError::Reqwest)?;
+
+    let status_code = response.status();
+    let headers = response.headers().to_owned();
+    let body = response
+        .bytes()
+        .await
+        .map_err(HttpClientError::Reqwest)?
+        .to_vec();
+
+    Ok(HttpResponse {
+        status_code,
+        headers,
+        body,
+    })
+}
@@ -5,90 +5,276 @@
 //! Provides [AuthToken]. This struct does all the bookkeeping to request Bearer tokens.
 //!
 //! ## Usage
-//! ```rust
+//! ```rust,no_run
+//! use std::env;
+//!
 //! use client_auth::AuthToken;
 //!
+//! # async fn run() {
 //! // You can use environment variables or hardcode these
 //! let client_id = env::var("CLIENT_ID").unwrap();
 //! let client_secret = env::var("CLIENT_SECRET").unwrap();
 //! let auth_endpoint = env::var("AUTH_ENDPOINT").unwrap();
 //! let token_endpoint = env::var("TOKEN_ENDPOINT").unwrap();
 //!
-//! // Create AuthToken instance
-//! let mut auth_token = AuthToken::new(
+//! // Spawn a background task that keeps the token fresh
+//! let auth_token = AuthToken::spawn(
 //!     client_id.clone(),
 //!     client_secret,
 //!     auth_endpoint,
 //!     token_endpoint,
 //! )
-//! .await;
+//! .await
+//! .unwrap();
 //!
+//! // auth_token is cheap to clone and can be shared across tasks
+//! let auth_token_clone = auth_token.clone();
 //!
 //! // When you need the token
-//! let token = auth_token.token().await;
+//! let token = auth_token.token().await.unwrap();
+//! # }
 //! ```
 
-use std::time::{Duration, SystemTime};
+mod authenticator;
+mod error;
+mod http;
+mod stored;
+mod token;
 
-use oauth2::{
-    basic::BasicClient, reqwest::async_http_client, AccessToken, AuthUrl, ClientId, ClientSecret,
-    TokenResponse, TokenUrl,
+pub use authenticator::{
+    AnonymousAuthenticator, Authenticator, AuthorizationCodeAuthenticator,
+    ClientCredentialsAuthenticator,
 };
+pub use error::AuthError;
+pub use stored::StoredCredentials;
+pub use token::{TokenPair, TokenType};
+
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use rand::Rng;
+use tokio::sync::RwLock;
+
+/// Base delay between retries when a background refresh fails.
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Maximum delay between retries when a background refresh keeps failing.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+struct Inner {
+    authenticator: Arc<dyn Authenticator>,
+    data: TokenPair,
+}
 
+/// A cloneable handle to a continuously-refreshed OAuth 2.0 access token.
+///
+/// Cloning an [AuthToken] is cheap: every clone shares the same underlying
+/// token via an [Arc], so [AuthToken::token] never blocks on network I/O.
+#[derive(Clone)]
 pub struct AuthToken {
-    client: BasicClient,
-    token: AccessToken,
-    issued_at: SystemTime,
-    expires_in: Duration,
+    inner: Arc<RwLock<Inner>>,
 }
 
 impl AuthToken {
+    /// Exchange client credentials for a token once, without keeping it fresh.
+    ///
+    /// Prefer [AuthToken::spawn] for long-running services; this is mainly
+    /// useful for one-shot invocations.
     pub async fn new(
         client_id: String,
         client_secret: String,
         auth_endpoint: String,
         token_endpoint: String,
-    ) -> Self {
-        let token_client = BasicClient::new(
-            ClientId::new(client_id),
-            Some(ClientSecret::new(client_secret)),
-            AuthUrl::new(auth_endpoint).unwrap(),
-            Some(TokenUrl::new(token_endpoint).unwrap()),
-        );
-
-        let token_response = token_client
-            .exchange_client_credentials()
-            .request_async(async_http_client)
-            .await
-            .unwrap();
-
-        AuthToken {
-            client: token_client,
-            token: token_response.access_token().to_owned(),
-            issued_at: SystemTime::now(),
-            expires_in: token_response
-                .expires_in()
-                .unwrap_or(Duration::from_secs(300)),
+    ) -> Result<Self, AuthError> {
+        let authenticator = ClientCredentialsAuthenticator::new(
+            client_id,
+            client_secret,
+            auth_endpoint,
+            token_endpoint,
+        )?;
+
+        AuthToken::from_authenticator(Arc::new(authenticator)).await
+    }
+
+    /// Exchange client credentials for a token and spawn a background task
+    /// that keeps it fresh until the returned handle (and all its clones)
+    /// are dropped.
+    ///
+    /// The background task wakes up roughly `expires_in - 15s` after each
+    /// successful exchange and re-requests a token. If a refresh fails it
+    /// retries with jittered exponential backoff instead of giving up.
+    pub async fn spawn(
+        client_id: String,
+        client_secret: String,
+        auth_endpoint: String,
+        token_endpoint: String,
+    ) -> Result<Self, AuthError> {
+        let authenticator = ClientCredentialsAuthenticator::new(
+            client_id,
+            client_secret,
+            auth_endpoint,
+            token_endpoint,
+        )?;
+
+        AuthToken::spawn_with(Arc::new(authenticator)).await
+    }
+
+    /// Rehydrate a token saved by [AuthToken::credentials], skipping the
+    /// initial client-credentials exchange if it is still valid, and spawn a
+    /// background task that keeps it fresh from then on.
+    ///
+    /// Useful for short-lived CLI invocations: stash the [StoredCredentials]
+    /// on disk between runs and reuse them here instead of hitting the token
+    /// endpoint every time.
+    pub async fn from_stored(
+        stored: StoredCredentials,
+        client_id: String,
+        client_secret: String,
+        auth_endpoint: String,
+        token_endpoint: String,
+    ) -> Result<Self, AuthError> {
+        let authenticator = ClientCredentialsAuthenticator::new(
+            client_id,
+            client_secret,
+            auth_endpoint,
+            token_endpoint,
+        )?;
+        let authenticator: Arc<dyn Authenticator> = Arc::new(authenticator);
+
+        let data: TokenPair = stored.into();
+        let data = if data.is_expired() {
+            authenticator.login().await?
+        } else {
+            data
+        };
+
+        let auth_token = AuthToken {
+            inner: Arc::new(RwLock::new(Inner { authenticator, data })),
+        };
+
+        tokio::spawn(AuthToken::token_daemon(Arc::downgrade(&auth_token.inner)));
+
+        Ok(auth_token)
+    }
+
+    /// Log in with an arbitrary [Authenticator] once, without keeping the
+    /// resulting token fresh.
+    ///
+    /// Prefer [AuthToken::spawn_with] for long-running services.
+    pub async fn from_authenticator(
+        authenticator: Arc<dyn Authenticator>,
+    ) -> Result<Self, AuthError> {
+        let data = authenticator.login().await?;
+
+        Ok(AuthToken {
+            inner: Arc::new(RwLock::new(Inner {
+                authenticator,
+                data,
+            })),
+        })
+    }
+
+    /// Log in with an arbitrary [Authenticator] and spawn a background task
+    /// that keeps the resulting token fresh until the returned handle (and
+    /// all its clones) are dropped.
+    pub async fn spawn_with(authenticator: Arc<dyn Authenticator>) -> Result<Self, AuthError> {
+        let auth_token = AuthToken::from_authenticator(authenticator).await?;
+
+        tokio::spawn(AuthToken::token_daemon(Arc::downgrade(&auth_token.inner)));
+
+        Ok(auth_token)
+    }
+
+    /// Background loop that keeps `inner` populated with a live token.
+    ///
+    /// Holds only a [Weak] reference so the daemon doesn't itself keep
+    /// `inner` alive: once the last [AuthToken] handle is dropped, the next
+    /// `upgrade` fails and the loop exits instead of running for the rest of
+    /// the process.
+    async fn token_daemon(inner: Weak<RwLock<Inner>>) {
+        let mut retry_delay = RETRY_BASE_DELAY;
+
+        loop {
+            let Some(strong) = inner.upgrade() else {
+                break;
+            };
+            let (authenticator, data) = {
+                let guard = strong.read().await;
+                (guard.authenticator.clone(), guard.data.clone())
+            };
+            drop(strong);
+
+            tokio::time::sleep(data.time_until_refresh()).await;
+
+            let Some(strong) = inner.upgrade() else {
+                break;
+            };
+
+            if !authenticator.needs_refresh(&data) {
+                continue;
+            }
+
+            match authenticator.refresh(&data).await {
+                Ok(fresh) => {
+                    strong.write().await.data = fresh;
+                    retry_delay = RETRY_BASE_DELAY;
+                }
+                Err(_) => {
+                    let jitter = rand::thread_rng().gen_range(0..500);
+                    tokio::time::sleep(retry_delay + Duration::from_millis(jitter)).await;
+                    retry_delay = (retry_delay * 2).min(RETRY_MAX_DELAY);
+                }
+            }
         }
     }
 
-    pub async fn token(&mut self) -> String {
-        // Request new token if old token is only valid for 15 seconds
-        if self.issued_at.elapsed().unwrap().as_secs() >= self.expires_in.as_secs() - 15 {
-            let token_response = self
-                .client
-                .exchange_client_credentials()
-                .request_async(async_http_client)
-                .await
-                .unwrap();
-
-            self.token = token_response.access_token().to_owned();
-            self.issued_at = SystemTime::now();
-            self.expires_in = token_response
-                .expires_in()
-                .unwrap_or(Duration::from_secs(300));
+    /// Clone the current access token's secret.
+    ///
+    /// This only takes a read lock, so it never blocks on network I/O and is
+    /// safe to call from many tasks concurrently.
+    ///
+    /// Returns [AuthError::Expired] if the cached token has fully expired,
+    /// which only happens if the background refresh from [AuthToken::spawn]
+    /// was never started or has fallen behind after repeated failures.
+    /// Returns [AuthError::UnexpectedTokenType] if the token endpoint issued
+    /// something other than a `Bearer` token.
+    pub async fn token(&self) -> Result<String, AuthError> {
+        let inner = self.inner.read().await;
+        if inner.data.is_expired() {
+            return Err(AuthError::Expired);
         }
+        match inner.data.token_type() {
+            TokenType::Bearer => Ok(inner.data.access_token.secret().to_owned()),
+            TokenType::Unrecognized(kind) => {
+                Err(AuthError::UnexpectedTokenType(kind.to_owned()))
+            }
+        }
+    }
+
+    /// The `Authorization` header value to send alongside the current token.
+    ///
+    /// Returns [AuthError::UnexpectedTokenType] if the token endpoint issued
+    /// something other than a `Bearer` token.
+    pub async fn authorization_header(&self) -> Result<String, AuthError> {
+        let inner = self.inner.read().await;
+        if inner.data.is_expired() {
+            return Err(AuthError::Expired);
+        }
+        if let TokenType::Unrecognized(kind) = inner.data.token_type() {
+            return Err(AuthError::UnexpectedTokenType(kind.to_owned()));
+        }
+        Ok(inner.authenticator.bearer(&inner.data))
+    }
+
+    /// The current [TokenPair], including its refresh token if one was
+    /// issued.
+    pub async fn token_pair(&self) -> TokenPair {
+        self.inner.read().await.data.clone()
+    }
 
-        self.token.secret().to_owned()
+    /// A serializable snapshot of the current credentials, suitable for
+    /// stashing on disk and later restoring via [AuthToken::from_stored].
+    pub async fn credentials(&self) -> StoredCredentials {
+        self.inner.read().await.data.clone().into()
     }
 }
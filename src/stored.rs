@@ -0,0 +1,48 @@
+use std::time::{Duration, SystemTime};
+
+use oauth2::{AccessToken, RefreshToken};
+use serde::{Deserialize, Serialize};
+
+use crate::token::{TokenPair, TokenType};
+
+/// A serializable snapshot of an [crate::AuthToken]'s live credentials.
+///
+/// Round-trip this through [AuthToken::credentials] and
+/// [AuthToken::from_stored] to persist a token across process restarts, e.g.
+/// so a short-lived CLI invocation can reuse a still-valid token instead of
+/// hitting the token endpoint every run.
+///
+/// [AuthToken::credentials]: crate::AuthToken::credentials
+/// [AuthToken::from_stored]: crate::AuthToken::from_stored
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StoredCredentials {
+    pub access_token: AccessToken,
+    pub refresh_token: Option<RefreshToken>,
+    pub issued_at: SystemTime,
+    pub expires_in: Duration,
+    pub token_type: TokenType,
+}
+
+impl From<TokenPair> for StoredCredentials {
+    fn from(data: TokenPair) -> Self {
+        StoredCredentials {
+            access_token: data.access_token,
+            refresh_token: data.refresh_token,
+            issued_at: data.issued_at,
+            expires_in: data.expires_in,
+            token_type: data.token_type,
+        }
+    }
+}
+
+impl From<StoredCredentials> for TokenPair {
+    fn from(stored: StoredCredentials) -> Self {
+        TokenPair {
+            access_token: stored.access_token,
+            refresh_token: stored.refresh_token,
+            issued_at: stored.issued_at,
+            expires_in: stored.expires_in,
+            token_type: stored.token_type,
+        }
+    }
+}
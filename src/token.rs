@@ -0,0 +1,128 @@
+use std::time::{Duration, SystemTime};
+
+use oauth2::{AccessToken, RefreshToken, TokenResponse};
+use serde::{Deserialize, Serialize};
+
+/// How long before expiry a token is considered stale and due for refresh.
+pub(crate) const REFRESH_MARGIN: Duration = Duration::from_secs(15);
+
+/// The kind of token issued by the token endpoint, as validated the way
+/// inth-oauth2 validates it: a `token_type` of anything but `"bearer"`
+/// (case-insensitively) is kept around verbatim instead of discarded, since
+/// it means the endpoint issued something this crate doesn't know how to use
+/// as a `Bearer` secret.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenType {
+    /// RFC 6750 Bearer token, the only kind [crate::AuthToken::token] will
+    /// hand out.
+    Bearer,
+    /// Any `token_type` other than `"bearer"`, preserved verbatim.
+    Unrecognized(String),
+}
+
+impl From<&str> for TokenType {
+    fn from(token_type: &str) -> Self {
+        if token_type.eq_ignore_ascii_case("bearer") {
+            TokenType::Bearer
+        } else {
+            TokenType::Unrecognized(token_type.to_owned())
+        }
+    }
+}
+
+/// A live access token, together with the optional refresh token needed to
+/// replace it and the bookkeeping needed to know when to do so.
+///
+/// Mirrors the split inth-oauth2 makes between a bare access token and a
+/// `Token` that also knows how to renew itself.
+#[derive(Clone)]
+pub struct TokenPair {
+    pub access_token: AccessToken,
+    pub refresh_token: Option<RefreshToken>,
+    pub(crate) issued_at: SystemTime,
+    pub(crate) expires_in: Duration,
+    pub(crate) token_type: TokenType,
+}
+
+impl TokenPair {
+    pub(crate) fn from_response<TR>(token_response: &TR) -> Self
+    where
+        TR: TokenResponse<oauth2::basic::BasicTokenType>,
+    {
+        TokenPair {
+            access_token: token_response.access_token().to_owned(),
+            refresh_token: token_response.refresh_token().cloned(),
+            issued_at: SystemTime::now(),
+            expires_in: token_response
+                .expires_in()
+                .unwrap_or(Duration::from_secs(300)),
+            token_type: TokenType::from(token_response.token_type().as_ref()),
+        }
+    }
+
+    /// How long until this token should be refreshed, `Duration::ZERO` if it
+    /// already should have been.
+    pub(crate) fn time_until_refresh(&self) -> Duration {
+        let elapsed = self.issued_at.elapsed().unwrap_or(Duration::ZERO);
+        self.expires_in
+            .saturating_sub(REFRESH_MARGIN)
+            .saturating_sub(elapsed)
+    }
+
+    /// Whether this token has fully expired (not just within the refresh
+    /// margin of expiring).
+    pub(crate) fn is_expired(&self) -> bool {
+        self.issued_at.elapsed().unwrap_or(Duration::ZERO) >= self.expires_in
+    }
+
+    /// When this token was issued.
+    pub fn issued_at(&self) -> SystemTime {
+        self.issued_at
+    }
+
+    /// How long this token is valid for from the moment it was issued.
+    pub fn expires_in(&self) -> Duration {
+        self.expires_in
+    }
+
+    /// When this token expires.
+    ///
+    /// Saturates to [TokenPair::issued_at] instead of panicking if
+    /// `expires_in` is large enough to overflow `SystemTime` (as with the
+    /// `Duration::MAX` sentinel [crate::AnonymousAuthenticator] issues).
+    pub fn expires_at(&self) -> SystemTime {
+        self.issued_at
+            .checked_add(self.expires_in)
+            .unwrap_or(self.issued_at)
+    }
+
+    /// How long until this token expires, `Duration::ZERO` if it already
+    /// has.
+    pub fn time_remaining(&self) -> Duration {
+        self.expires_in
+            .saturating_sub(self.issued_at.elapsed().unwrap_or(Duration::ZERO))
+    }
+
+    /// The kind of token this is, as reported by the token endpoint.
+    pub fn token_type(&self) -> &TokenType {
+        &self.token_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_at_does_not_overflow_for_duration_max() {
+        let pair = TokenPair {
+            access_token: AccessToken::new(String::new()),
+            refresh_token: None,
+            issued_at: SystemTime::now(),
+            expires_in: Duration::MAX,
+            token_type: TokenType::Bearer,
+        };
+
+        assert_eq!(pair.expires_at(), pair.issued_at());
+    }
+}